@@ -1,59 +1,54 @@
-use std::fmt::Formatter;
+use std::{
+    error::Error as StdError,
+    fmt::{Display, Formatter},
+};
+
+#[cfg(feature = "unknown-fields")]
+use std::collections::HashMap;
 
 use crate::id::{
     marker::{EmojiMarker, TagMarker},
     Id,
 };
+#[cfg(feature = "unknown-fields")]
+use serde::ser::SerializeMap;
 use serde::{
     de::{Error, IgnoredAny, MapAccess, Visitor},
-    Deserialize, Serialize,
+    ser::SerializeStruct,
+    Deserialize, Serialize, Serializer,
 };
 use serde_value::Value;
 
-/// Emoji to use as the default way to react to a forum post.
+/// Emoji used as a reaction, either a custom guild emoji or a Unicode emoji.
 ///
-/// Exactly one of `emoji_id` and `emoji_name` must be set.
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
-pub struct DefaultReaction {
-    /// ID of custom guild emoji.
-    ///
-    /// Conflicts with `emoji_name`.
-    pub emoji_id: Option<Id<EmojiMarker>>,
+/// On the wire this is represented as a pair of `emoji_id`/`emoji_name`
+/// fields of which at most one is set; this type makes the "exactly one of
+/// the two" invariant impossible to violate once constructed.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum ReactionEmoji {
+    /// Custom guild emoji.
+    Custom(Id<EmojiMarker>),
     /// Unicode emoji character.
-    ///
-    /// Conflicts with `emoji_id`.
-    pub emoji_name: Option<String>,
+    Unicode(String),
 }
 
-/// Tag that is able to be applied to a thread in a [`GuildForum`] [`Channel`].
-///
-/// May at most contain one of `emoji_id` and `emoji_name`.
-///
-/// [`Channel`]: super::Channel
-/// [`GuildForum`]: super::ChannelType::GuildForum
-#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize)]
-pub struct ForumTag {
-    /// ID of custom guild emoji.
-    ///
-    /// Conflicts with `emoji_name`.
-    pub emoji_id: Option<Id<EmojiMarker>>,
-    /// Unicode emoji character.
-    ///
-    /// Conflicts with `emoji_name`.
-    pub emoji_name: Option<String>,
-    /// ID of the tag.
-    pub id: Id<TagMarker>,
-    /// Whether the tag can only be added or removed by [`Member`]s with the
-    /// [`MANAGE_THREADS`] permission.
-    ///
-    /// [`MANAGE_THREADS`]: crate::guild::Permissions::MANAGE_THREADS
-    /// [`Member`]: crate::guild::Member
-    pub moderated: bool,
-    /// Name of the tag (0--20 characters).
-    pub name: String,
+/// Emoji to use as the default way to react to a forum post.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct DefaultReaction {
+    /// Emoji to react with.
+    pub emoji: Option<ReactionEmoji>,
 }
 
-impl<'de> Deserialize<'de> for ForumTag {
+impl Serialize for DefaultReaction {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("DefaultReaction", 2)?;
+        serialize_reaction_emoji_fields(&mut state, self.emoji.as_ref())?;
+
+        state.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for DefaultReaction {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
@@ -63,26 +58,20 @@ impl<'de> Deserialize<'de> for ForumTag {
         enum Field {
             EmojiId,
             EmojiName,
-            Id,
-            Moderated,
-            Name,
         }
 
-        struct ForumTagVisitor;
+        struct DefaultReactionVisitor;
 
-        impl<'de> Visitor<'de> for ForumTagVisitor {
-            type Value = ForumTag;
+        impl<'de> Visitor<'de> for DefaultReactionVisitor {
+            type Value = DefaultReaction;
 
             fn expecting(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-                f.write_str("struct ForumTag")
+                f.write_str("struct DefaultReaction")
             }
 
             fn visit_map<V: MapAccess<'de>>(self, mut map: V) -> Result<Self::Value, V::Error> {
-                let mut emoji_id = None::<Id<_>>;
+                let mut emoji_id = None::<Id<EmojiMarker>>;
                 let mut emoji_name = None::<Option<String>>;
-                let mut id = None::<Id<TagMarker>>;
-                let mut moderated = None::<bool>;
-                let mut name = None::<String>;
 
                 loop {
                     let key = match map.next_key() {
@@ -103,28 +92,7 @@ impl<'de> Deserialize<'de> for ForumTag {
                                 return Err(Error::duplicate_field("emoji_id"));
                             }
 
-                            let value: Value = map.next_value()?;
-
-                            let possible_id = match value {
-                                Value::U64(val) => Some(val),
-                                Value::Option(Some(value)) => match *value {
-                                    Value::Newtype(newtype) => {
-                                        if let Value::String(string) = *newtype {
-                                            Some(string.parse::<u64>().unwrap())
-                                        } else {
-                                            None
-                                        }
-                                    }
-                                    _ => None,
-                                },
-                                _ => None,
-                            };
-
-                            if let Some(id) = possible_id {
-                                if id > 0 {
-                                    emoji_id = Some(Id::new(id));
-                                }
-                            }
+                            emoji_id = parse_emoji_id(map.next_value()?)?;
                         }
                         Field::EmojiName => {
                             if emoji_name.is_some() {
@@ -133,36 +101,167 @@ impl<'de> Deserialize<'de> for ForumTag {
 
                             emoji_name = Some(map.next_value()?);
                         }
-                        Field::Id => {
+                    }
+                }
+
+                Ok(DefaultReaction {
+                    emoji: reaction_emoji_from_parts(emoji_id, emoji_name.unwrap_or_default()),
+                })
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "DefaultReaction",
+            &["emoji_id", "emoji_name"],
+            DefaultReactionVisitor,
+        )
+    }
+}
+
+/// Tag that is able to be applied to a thread in a [`GuildForum`] [`Channel`].
+///
+/// [`Channel`]: super::Channel
+/// [`GuildForum`]: super::ChannelType::GuildForum
+#[derive(Clone, Debug, Eq, PartialEq)]
+// `unknown`'s `HashMap` isn't `Hash`, so only derive it when that field is absent.
+#[cfg_attr(not(feature = "unknown-fields"), derive(Hash))]
+pub struct ForumTag {
+    /// Emoji to use as the tag's icon.
+    pub emoji: Option<ReactionEmoji>,
+    /// ID of the tag.
+    pub id: Id<TagMarker>,
+    /// Whether the tag can only be added or removed by [`Member`]s with the
+    /// [`MANAGE_THREADS`] permission.
+    ///
+    /// [`MANAGE_THREADS`]: crate::guild::Permissions::MANAGE_THREADS
+    /// [`Member`]: crate::guild::Member
+    pub moderated: bool,
+    /// Name of the tag (0--20 characters).
+    pub name: String,
+    /// Fields sent by Discord that aren't yet known to this library.
+    ///
+    /// Gated behind the `unknown-fields` feature: without it, unrecognized
+    /// keys are deserialized and discarded as before, keeping the default
+    /// wire shape unaffected.
+    #[cfg(feature = "unknown-fields")]
+    pub unknown: HashMap<String, Value>,
+}
+
+#[cfg(not(feature = "unknown-fields"))]
+impl Serialize for ForumTag {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("ForumTag", 5)?;
+        serialize_reaction_emoji_fields(&mut state, self.emoji.as_ref())?;
+        state.serialize_field("id", &self.id)?;
+        state.serialize_field("moderated", &self.moderated)?;
+        state.serialize_field("name", &self.name)?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "unknown-fields")]
+impl Serialize for ForumTag {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // Serialized as a map rather than a fixed struct so the
+        // `unknown`-field entries (dynamic keys) can be flattened back in
+        // alongside the known ones.
+        let mut map = serializer.serialize_map(None)?;
+        serialize_reaction_emoji_entries(&mut map, self.emoji.as_ref())?;
+        map.serialize_entry("id", &self.id)?;
+        map.serialize_entry("moderated", &self.moderated)?;
+        map.serialize_entry("name", &self.name)?;
+
+        for (key, value) in &self.unknown {
+            map.serialize_entry(key, value)?;
+        }
+
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for ForumTag {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct ForumTagVisitor;
+
+        impl<'de> Visitor<'de> for ForumTagVisitor {
+            type Value = ForumTag;
+
+            fn expecting(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+                f.write_str("struct ForumTag")
+            }
+
+            fn visit_map<V: MapAccess<'de>>(self, mut map: V) -> Result<Self::Value, V::Error> {
+                let mut emoji_id = None::<Id<EmojiMarker>>;
+                let mut emoji_name = None::<Option<String>>;
+                let mut id = None::<Id<TagMarker>>;
+                let mut moderated = None::<bool>;
+                let mut name = None::<String>;
+                #[cfg(feature = "unknown-fields")]
+                let mut unknown = HashMap::new();
+
+                // Keys are read as raw strings rather than through a
+                // `#[serde(field_identifier)]` enum so that any key not
+                // matched below can still be captured by its original name
+                // instead of being lost behind a deserialize error.
+                while let Some(key) = map.next_key::<String>()? {
+                    match key.as_str() {
+                        "emoji_id" => {
+                            if emoji_id.is_some() {
+                                return Err(Error::duplicate_field("emoji_id"));
+                            }
+
+                            emoji_id = parse_emoji_id(map.next_value()?)?;
+                        }
+                        "emoji_name" => {
+                            if emoji_name.is_some() {
+                                return Err(Error::duplicate_field("emoji_name"));
+                            }
+
+                            emoji_name = Some(map.next_value()?);
+                        }
+                        "id" => {
                             if id.is_some() {
                                 return Err(Error::duplicate_field("id"));
                             }
 
                             id = Some(map.next_value()?);
                         }
-                        Field::Moderated => {
+                        "moderated" => {
                             if moderated.is_some() {
                                 return Err(Error::duplicate_field("moderated"));
                             }
 
                             moderated = Some(map.next_value()?);
                         }
-                        Field::Name => {
+                        "name" => {
                             if name.is_some() {
                                 return Err(Error::duplicate_field("name"));
                             }
 
                             name = Some(map.next_value()?);
                         }
+                        #[cfg(feature = "unknown-fields")]
+                        other => {
+                            unknown.insert(other.to_owned(), map.next_value()?);
+                        }
+                        #[cfg(not(feature = "unknown-fields"))]
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
                     }
                 }
 
                 Ok(ForumTag {
-                    emoji_id,
-                    emoji_name: emoji_name.unwrap_or_default(),
+                    emoji: reaction_emoji_from_parts(emoji_id, emoji_name.unwrap_or_default()),
                     id: id.ok_or_else(|| Error::missing_field("id"))?,
                     moderated: moderated.ok_or_else(|| Error::missing_field("moderated"))?,
                     name: name.ok_or_else(|| Error::missing_field("name"))?,
+                    #[cfg(feature = "unknown-fields")]
+                    unknown,
                 })
             }
         }
@@ -175,9 +274,369 @@ impl<'de> Deserialize<'de> for ForumTag {
     }
 }
 
+/// Combines a decoded `emoji_id`/`emoji_name` pair into a [`ReactionEmoji`].
+///
+/// Discord never sends both fields set, but if a payload (invalidly) does,
+/// the custom emoji wins and `emoji_name` is silently discarded.
+fn reaction_emoji_from_parts(
+    emoji_id: Option<Id<EmojiMarker>>,
+    emoji_name: Option<String>,
+) -> Option<ReactionEmoji> {
+    match (emoji_id, emoji_name) {
+        (Some(id), _) => Some(ReactionEmoji::Custom(id)),
+        (None, Some(name)) => Some(ReactionEmoji::Unicode(name)),
+        (None, None) => None,
+    }
+}
+
+/// Writes the wire `emoji_id`/`emoji_name` pair for a [`ReactionEmoji`].
+fn serialize_reaction_emoji_fields<S: SerializeStruct>(
+    state: &mut S,
+    emoji: Option<&ReactionEmoji>,
+) -> Result<(), S::Error> {
+    match emoji {
+        Some(ReactionEmoji::Custom(id)) => {
+            state.serialize_field("emoji_id", &Some(id))?;
+            state.serialize_field("emoji_name", &None::<String>)?;
+        }
+        Some(ReactionEmoji::Unicode(name)) => {
+            state.serialize_field("emoji_id", &None::<Id<EmojiMarker>>)?;
+            state.serialize_field("emoji_name", &Some(name))?;
+        }
+        None => {
+            state.serialize_field("emoji_id", &None::<Id<EmojiMarker>>)?;
+            state.serialize_field("emoji_name", &None::<String>)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Map-based counterpart to [`serialize_reaction_emoji_fields`].
+#[cfg(feature = "unknown-fields")]
+fn serialize_reaction_emoji_entries<S: SerializeMap>(
+    map: &mut S,
+    emoji: Option<&ReactionEmoji>,
+) -> Result<(), S::Error> {
+    match emoji {
+        Some(ReactionEmoji::Custom(id)) => {
+            map.serialize_entry("emoji_id", &Some(id))?;
+            map.serialize_entry("emoji_name", &None::<String>)?;
+        }
+        Some(ReactionEmoji::Unicode(name)) => {
+            map.serialize_entry("emoji_id", &None::<Id<EmojiMarker>>)?;
+            map.serialize_entry("emoji_name", &Some(name))?;
+        }
+        None => {
+            map.serialize_entry("emoji_id", &None::<Id<EmojiMarker>>)?;
+            map.serialize_entry("emoji_name", &None::<String>)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Parses a raw `emoji_id` value into a snowflake, accepting integers of any
+/// width and numeric strings, optionally wrapped in an `Option`/newtype
+/// layer. `0` and `"0"` are treated as "no custom emoji".
+fn parse_emoji_id<E: Error>(value: Value) -> Result<Option<Id<EmojiMarker>>, E> {
+    let id = match value {
+        Value::Unit => return Ok(None),
+        Value::Option(inner) => return inner.map_or(Ok(None), |inner| parse_emoji_id(*inner)),
+        Value::Newtype(inner) => return parse_emoji_id(*inner),
+        Value::U8(val) => u64::from(val),
+        Value::U16(val) => u64::from(val),
+        Value::U32(val) => u64::from(val),
+        Value::U64(val) => val,
+        Value::I8(val) => non_negative_emoji_id(val)?,
+        Value::I16(val) => non_negative_emoji_id(val)?,
+        Value::I32(val) => non_negative_emoji_id(val)?,
+        Value::I64(val) => non_negative_emoji_id(val)?,
+        Value::String(ref string) => string
+            .parse()
+            .map_err(|_| Error::custom(format!("emoji_id is not a valid snowflake: {string:?}")))?,
+        other => {
+            return Err(Error::custom(format!(
+                "emoji_id has an unsupported type: {other:?}"
+            )))
+        }
+    };
+
+    Ok((id > 0).then(|| Id::new(id)))
+}
+
+/// Converts a signed `emoji_id` representation to `u64`, rejecting negatives.
+fn non_negative_emoji_id<E: Error>(
+    value: impl TryInto<u64> + std::fmt::Display + Copy,
+) -> Result<u64, E> {
+    value
+        .try_into()
+        .map_err(|_| Error::custom(format!("emoji_id is negative: {value}")))
+}
+
+/// Failure returned by [`ForumTagBuilder::build`] or
+/// [`DefaultReactionBuilder::build`] when the provided fields don't satisfy
+/// Discord's documented constraints.
+#[derive(Debug)]
+pub struct ForumTagValidationError {
+    /// Type of error that occurred.
+    kind: ForumTagValidationErrorType,
+}
+
+impl ForumTagValidationError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &ForumTagValidationErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[allow(clippy::unused_self)]
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn StdError + Send + Sync>> {
+        None
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        ForumTagValidationErrorType,
+        Option<Box<dyn StdError + Send + Sync>>,
+    ) {
+        (self.kind, None)
+    }
+
+    const fn emoji_conflict() -> Self {
+        Self {
+            kind: ForumTagValidationErrorType::EmojiConflict,
+        }
+    }
+
+    const fn name_too_long(len: usize) -> Self {
+        Self {
+            kind: ForumTagValidationErrorType::NameTooLong { len },
+        }
+    }
+}
+
+impl Display for ForumTagValidationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.kind {
+            ForumTagValidationErrorType::EmojiConflict => {
+                f.write_str("at most one of a custom emoji and a Unicode emoji may be set")
+            }
+            ForumTagValidationErrorType::NameTooLong { len } => {
+                f.write_str("tag name is ")?;
+                Display::fmt(len, f)?;
+                f.write_str(" characters long, but must not be longer than 20 characters")
+            }
+        }
+    }
+}
+
+impl StdError for ForumTagValidationError {}
+
+/// Type of [`ForumTagValidationError`] that occurred.
+#[derive(Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ForumTagValidationErrorType {
+    /// Both a custom emoji and a Unicode emoji were provided; at most one
+    /// may be set.
+    EmojiConflict,
+    /// Tag name is longer than the 20 characters Discord allows.
+    NameTooLong {
+        /// Number of characters provided.
+        len: usize,
+    },
+}
+
+/// Combines a builder's `custom_emoji`/`unicode_emoji` setters into a
+/// [`ReactionEmoji`], rejecting the case where both were set.
+fn build_reaction_emoji(
+    custom_emoji: Option<Id<EmojiMarker>>,
+    unicode_emoji: Option<String>,
+) -> Result<Option<ReactionEmoji>, ForumTagValidationError> {
+    match (custom_emoji, unicode_emoji) {
+        (Some(_), Some(_)) => Err(ForumTagValidationError::emoji_conflict()),
+        (Some(id), None) => Ok(Some(ReactionEmoji::Custom(id))),
+        (None, Some(name)) => Ok(Some(ReactionEmoji::Unicode(name))),
+        (None, None) => Ok(None),
+    }
+}
+
+/// Builder for a [`ForumTag`], validating Discord's documented constraints
+/// (a 0--20 character name, and at most one emoji) before constructing the
+/// type.
+#[derive(Clone, Debug)]
+pub struct ForumTagBuilder {
+    custom_emoji: Option<Id<EmojiMarker>>,
+    id: Id<TagMarker>,
+    moderated: bool,
+    name: String,
+    unicode_emoji: Option<String>,
+}
+
+impl ForumTagBuilder {
+    /// Creates a new builder for the tag with the given ID and name.
+    pub fn new(id: Id<TagMarker>, name: impl Into<String>) -> Self {
+        Self {
+            custom_emoji: None,
+            id,
+            moderated: false,
+            name: name.into(),
+            unicode_emoji: None,
+        }
+    }
+
+    /// Sets the tag's name.
+    #[must_use = "has no effect if not built into a ForumTag"]
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+
+        self
+    }
+
+    /// Sets the tag's icon to a custom guild emoji.
+    ///
+    /// Conflicts with [`unicode_emoji`]; setting both makes [`build`]
+    /// return [`ForumTagValidationErrorType::EmojiConflict`].
+    ///
+    /// [`build`]: Self::build
+    /// [`unicode_emoji`]: Self::unicode_emoji
+    #[must_use = "has no effect if not built into a ForumTag"]
+    pub const fn custom_emoji(mut self, emoji_id: Id<EmojiMarker>) -> Self {
+        self.custom_emoji = Some(emoji_id);
+
+        self
+    }
+
+    /// Sets the tag's icon to a Unicode emoji.
+    ///
+    /// Conflicts with [`custom_emoji`]; setting both makes [`build`]
+    /// return [`ForumTagValidationErrorType::EmojiConflict`].
+    ///
+    /// [`build`]: Self::build
+    /// [`custom_emoji`]: Self::custom_emoji
+    #[must_use = "has no effect if not built into a ForumTag"]
+    pub fn unicode_emoji(mut self, emoji: impl Into<String>) -> Self {
+        self.unicode_emoji = Some(emoji.into());
+
+        self
+    }
+
+    /// Sets whether the tag can only be added or removed by [`Member`]s with
+    /// the [`MANAGE_THREADS`] permission.
+    ///
+    /// [`MANAGE_THREADS`]: crate::guild::Permissions::MANAGE_THREADS
+    /// [`Member`]: crate::guild::Member
+    #[must_use = "has no effect if not built into a ForumTag"]
+    pub const fn moderated(mut self, moderated: bool) -> Self {
+        self.moderated = moderated;
+
+        self
+    }
+
+    /// Consumes the builder, returning a validated [`ForumTag`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ForumTagValidationErrorType::NameTooLong`] if the name is
+    /// longer than 20 characters.
+    ///
+    /// Returns [`ForumTagValidationErrorType::EmojiConflict`] if both
+    /// [`custom_emoji`] and [`unicode_emoji`] were set.
+    ///
+    /// [`custom_emoji`]: Self::custom_emoji
+    /// [`unicode_emoji`]: Self::unicode_emoji
+    pub fn build(self) -> Result<ForumTag, ForumTagValidationError> {
+        let len = self.name.chars().count();
+
+        if len > 20 {
+            return Err(ForumTagValidationError::name_too_long(len));
+        }
+
+        let emoji = build_reaction_emoji(self.custom_emoji, self.unicode_emoji)?;
+
+        Ok(ForumTag {
+            emoji,
+            id: self.id,
+            moderated: self.moderated,
+            name: self.name,
+            #[cfg(feature = "unknown-fields")]
+            unknown: HashMap::new(),
+        })
+    }
+}
+
+/// Builder for a [`DefaultReaction`], validating that at most one of a
+/// custom emoji and a Unicode emoji is set before constructing the type.
+#[derive(Clone, Debug, Default)]
+pub struct DefaultReactionBuilder {
+    custom_emoji: Option<Id<EmojiMarker>>,
+    unicode_emoji: Option<String>,
+}
+
+impl DefaultReactionBuilder {
+    /// Creates a new, empty builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the reaction's icon to a custom guild emoji.
+    ///
+    /// Conflicts with [`unicode_emoji`]; setting both makes [`build`]
+    /// return [`ForumTagValidationErrorType::EmojiConflict`].
+    ///
+    /// [`build`]: Self::build
+    /// [`unicode_emoji`]: Self::unicode_emoji
+    #[must_use = "has no effect if not built into a DefaultReaction"]
+    pub const fn custom_emoji(mut self, emoji_id: Id<EmojiMarker>) -> Self {
+        self.custom_emoji = Some(emoji_id);
+
+        self
+    }
+
+    /// Sets the reaction's icon to a Unicode emoji.
+    ///
+    /// Conflicts with [`custom_emoji`]; setting both makes [`build`]
+    /// return [`ForumTagValidationErrorType::EmojiConflict`].
+    ///
+    /// [`build`]: Self::build
+    /// [`custom_emoji`]: Self::custom_emoji
+    #[must_use = "has no effect if not built into a DefaultReaction"]
+    pub fn unicode_emoji(mut self, emoji: impl Into<String>) -> Self {
+        self.unicode_emoji = Some(emoji.into());
+
+        self
+    }
+
+    /// Consumes the builder, returning a validated [`DefaultReaction`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ForumTagValidationErrorType::EmojiConflict`] if both
+    /// [`custom_emoji`] and [`unicode_emoji`] were set.
+    ///
+    /// [`custom_emoji`]: Self::custom_emoji
+    /// [`unicode_emoji`]: Self::unicode_emoji
+    pub fn build(self) -> Result<DefaultReaction, ForumTagValidationError> {
+        let emoji = build_reaction_emoji(self.custom_emoji, self.unicode_emoji)?;
+
+        Ok(DefaultReaction { emoji })
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{DefaultReaction, ForumTag};
+    #[cfg(feature = "unknown-fields")]
+    use std::collections::HashMap;
+
+    use super::{
+        DefaultReaction, DefaultReactionBuilder, ForumTag, ForumTagBuilder,
+        ForumTagValidationErrorType, ReactionEmoji,
+    };
     use crate::id::{
         marker::{EmojiMarker, TagMarker},
         Id,
@@ -190,8 +649,7 @@ mod tests {
     #[test]
     fn default_reaction() {
         let value = DefaultReaction {
-            emoji_id: None,
-            emoji_name: Some("name".to_owned()),
+            emoji: Some(ReactionEmoji::Unicode("name".to_owned())),
         };
 
         serde_test::assert_tokens(
@@ -214,36 +672,43 @@ mod tests {
     #[test]
     fn forum_tag() {
         let value = ForumTag {
-            emoji_id: Some(EMOJI_ID),
-            emoji_name: None,
+            emoji: Some(ReactionEmoji::Custom(EMOJI_ID)),
             id: TAG_ID,
             moderated: false,
             name: "other".into(),
+            #[cfg(feature = "unknown-fields")]
+            unknown: HashMap::new(),
         };
 
-        serde_test::assert_de_tokens(
-            &value,
-            &[
-                Token::Struct {
-                    name: "ForumTag",
-                    len: 5,
-                },
-                Token::Str("emoji_id"),
-                Token::Some,
-                Token::NewtypeStruct { name: "Id" },
-                Token::Str("1"),
-                Token::Str("emoji_name"),
-                Token::None,
-                Token::Str("id"),
-                Token::NewtypeStruct { name: "Id" },
-                Token::Str("2"),
-                Token::Str("moderated"),
-                Token::Bool(false),
-                Token::Str("name"),
-                Token::Str("other"),
-                Token::StructEnd,
-            ],
-        );
+        let tokens = [
+            Token::Struct {
+                name: "ForumTag",
+                len: 5,
+            },
+            Token::Str("emoji_id"),
+            Token::Some,
+            Token::NewtypeStruct { name: "Id" },
+            Token::Str("1"),
+            Token::Str("emoji_name"),
+            Token::None,
+            Token::Str("id"),
+            Token::NewtypeStruct { name: "Id" },
+            Token::Str("2"),
+            Token::Str("moderated"),
+            Token::Bool(false),
+            Token::Str("name"),
+            Token::Str("other"),
+            Token::StructEnd,
+        ];
+
+        // With `unknown-fields` on, `ForumTag` serializes as a map rather
+        // than a fixed-length struct, so only deserialization is checked
+        // against these struct tokens here; the map shape is covered by
+        // `forum_tag_unknown_fields_round_trip`.
+        #[cfg(not(feature = "unknown-fields"))]
+        serde_test::assert_tokens(&value, &tokens);
+        #[cfg(feature = "unknown-fields")]
+        serde_test::assert_de_tokens(&value, &tokens);
     }
 
     #[test]
@@ -254,7 +719,117 @@ mod tests {
 
         let tag = deserialized.unwrap();
 
-        assert!(tag.emoji_id.is_none());
-        assert!(tag.emoji_name.is_some());
+        assert_eq!(
+            tag.emoji,
+            Some(ReactionEmoji::Unicode("emoji_name".to_owned()))
+        );
+    }
+
+    #[test]
+    fn forum_tag_emoji_id_string_quoted() {
+        let deserialized = serde_json::from_str::<ForumTag>("{\n   \"name\":\"other\",\n   \"moderated\":false,\n   \"id\":\"2\",\n   \"emoji_name\":null,\n   \"emoji_id\":\"1\"\n}");
+
+        assert_eq!(
+            deserialized.unwrap().emoji,
+            Some(ReactionEmoji::Custom(EMOJI_ID))
+        );
+    }
+
+    #[test]
+    fn forum_tag_emoji_id_malformed_errors_instead_of_panicking() {
+        let deserialized = serde_json::from_str::<ForumTag>("{\n   \"name\":\"other\",\n   \"moderated\":false,\n   \"id\":\"2\",\n   \"emoji_name\":null,\n   \"emoji_id\":\"not-a-snowflake\"\n}");
+
+        assert!(deserialized.is_err());
+    }
+
+    #[test]
+    fn forum_tag_both_emoji_fields_set() {
+        let deserialized = serde_json::from_str::<ForumTag>("{\n   \"name\":\"other\",\n   \"moderated\":false,\n   \"id\":\"2\",\n   \"emoji_name\":\"emoji_name\",\n   \"emoji_id\":\"1\"\n}");
+
+        assert_eq!(
+            deserialized.unwrap().emoji,
+            Some(ReactionEmoji::Custom(EMOJI_ID))
+        );
+    }
+
+    #[cfg(feature = "unknown-fields")]
+    #[test]
+    fn forum_tag_unknown_fields_round_trip() {
+        let json = "{\n   \"name\":\"other\",\n   \"moderated\":false,\n   \"id\":\"2\",\n   \"emoji_name\":null,\n   \"emoji_id\":null,\n   \"icon_name\":\"fire\"\n}";
+
+        let tag = serde_json::from_str::<ForumTag>(json).unwrap();
+
+        assert_eq!(
+            tag.unknown.get("icon_name"),
+            Some(&serde_value::Value::String("fire".to_owned()))
+        );
+
+        let round_tripped =
+            serde_json::from_str::<ForumTag>(&serde_json::to_string(&tag).unwrap()).unwrap();
+
+        assert_eq!(tag, round_tripped);
+    }
+
+    #[test]
+    fn forum_tag_builder() {
+        let tag = ForumTagBuilder::new(TAG_ID, "other")
+            .custom_emoji(EMOJI_ID)
+            .moderated(true)
+            .build()
+            .unwrap();
+
+        assert_eq!(tag.emoji, Some(ReactionEmoji::Custom(EMOJI_ID)));
+        assert_eq!(tag.id, TAG_ID);
+        assert!(tag.moderated);
+        assert_eq!(tag.name, "other");
+    }
+
+    #[test]
+    fn forum_tag_builder_name_too_long() {
+        let name = "a".repeat(21);
+
+        assert_eq!(
+            ForumTagBuilder::new(TAG_ID, name)
+                .build()
+                .unwrap_err()
+                .kind(),
+            &ForumTagValidationErrorType::NameTooLong { len: 21 }
+        );
+    }
+
+    #[test]
+    fn forum_tag_builder_emoji_conflict() {
+        let result = ForumTagBuilder::new(TAG_ID, "other")
+            .custom_emoji(EMOJI_ID)
+            .unicode_emoji("name")
+            .build();
+
+        assert_eq!(
+            result.unwrap_err().kind(),
+            &ForumTagValidationErrorType::EmojiConflict
+        );
+    }
+
+    #[test]
+    fn default_reaction_builder() {
+        let reaction = DefaultReactionBuilder::new()
+            .unicode_emoji("name")
+            .build()
+            .unwrap();
+
+        assert_eq!(reaction.emoji, Some(ReactionEmoji::Unicode("name".into())));
+    }
+
+    #[test]
+    fn default_reaction_builder_emoji_conflict() {
+        let result = DefaultReactionBuilder::new()
+            .custom_emoji(EMOJI_ID)
+            .unicode_emoji("name")
+            .build();
+
+        assert_eq!(
+            result.unwrap_err().kind(),
+            &ForumTagValidationErrorType::EmojiConflict
+        );
     }
 }